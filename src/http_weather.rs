@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+
+use crate::{Weather, WeatherError, WeatherService};
+
+// Fetches a METAR-style conditions report over HTTP and maps it onto
+// the crate's `Weather` enum.
+pub struct HttpWeatherService {
+    client: Client,
+    endpoint: String,
+}
+
+impl HttpWeatherService {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            client: Client::new(),
+            endpoint: endpoint.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl WeatherService for HttpWeatherService {
+    async fn get_weather(&self) -> Result<(Weather, i8), WeatherError> {
+        let report: MetarReport = self
+            .client
+            .get(&self.endpoint)
+            .send()
+            .await
+            .map_err(|err| WeatherError::Request(err.to_string()))?
+            .error_for_status()
+            .map_err(|err| WeatherError::Request(err.to_string()))?
+            .json()
+            .await
+            .map_err(|err| WeatherError::Request(err.to_string()))?;
+
+        let weather = map_condition(&report.condition)?;
+        Ok((weather, report.wind_speed_kt))
+    }
+}
+
+#[derive(Deserialize)]
+struct MetarReport {
+    condition: String,
+    wind_speed_kt: i8,
+}
+
+fn map_condition(code: &str) -> Result<Weather, WeatherError> {
+    match code {
+        "TS" => Ok(Weather::Stormy),
+        "RA" => Ok(Weather::Raining),
+        "SN" => Ok(Weather::Snowing),
+        "GR" | "GS" => Ok(Weather::Hailing),
+        "SKC" | "CLR" => Ok(Weather::Clear),
+        "FEW" | "SCT" | "BKN" | "OVC" => Ok(Weather::Cloudy),
+        "" => Ok(Weather::Sunny),
+        other => Err(WeatherError::UnrecognizedCondition(other.to_string())),
+    }
+}
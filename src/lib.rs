@@ -1,59 +1,331 @@
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
 use mockall::predicate::*;
 use mockall::*;
 
+mod http_weather;
+mod network;
+
+pub use http_weather::HttpWeatherService;
+pub use network::AirportNetwork;
+
+const DEFAULT_MAX_RETRY_ATTEMPTS: u32 = 3;
+const DEFAULT_RUNWAYS: usize = 4;
+const DEFAULT_MAX_WIND: i8 = 40;
+
 pub struct AirtrafficController {
     airport_max_capacity: usize,
     airport_capacity: usize,
     plane_ids: Vec<u8>,
     weather_service: Box<dyn WeatherService>,
+    holding_pattern: VecDeque<(Plane, u32, SystemTime)>,
+    retry_policy: Box<dyn RetryPolicy>,
+    available_runways: Rc<Cell<usize>>,
+    active_permits: HashMap<u8, RunwayPermit>,
+    max_landing_wind: i8,
+    max_takeoff_wind: i8,
+    events: Vec<ControllerEvent>,
 }
 
 impl AirtrafficController {
     fn new(weather_service: Box<dyn WeatherService>, initial_planes: Vec<u8>) -> Self {
+        Self::with_runways(weather_service, initial_planes, DEFAULT_RUNWAYS)
+    }
+
+    fn with_runways(
+        weather_service: Box<dyn WeatherService>,
+        initial_planes: Vec<u8>,
+        runways: usize,
+    ) -> Self {
         Self {
             airport_capacity: initial_planes.len(),
             airport_max_capacity: 100,
             plane_ids: initial_planes,
             weather_service,
+            holding_pattern: VecDeque::new(),
+            retry_policy: Box::new(ExponentialBackoff::new(
+                Duration::from_secs(1),
+                DEFAULT_MAX_RETRY_ATTEMPTS,
+            )),
+            available_runways: Rc::new(Cell::new(runways)),
+            active_permits: HashMap::new(),
+            max_landing_wind: DEFAULT_MAX_WIND,
+            max_takeoff_wind: DEFAULT_MAX_WIND,
+            events: Vec::new(),
+        }
+    }
+
+    fn set_retry_policy(&mut self, retry_policy: Box<dyn RetryPolicy>) {
+        self.retry_policy = retry_policy;
+    }
+
+    fn set_max_landing_wind(&mut self, max: i8) {
+        self.max_landing_wind = max;
+    }
+
+    fn set_max_takeoff_wind(&mut self, max: i8) {
+        self.max_takeoff_wind = max;
+    }
+
+    // Acquires a runway slot, returning `None` if every runway is already
+    // occupied by an in-progress landing or takeoff.
+    fn acquire_runway(&self) -> Option<RunwayPermit> {
+        let available = self.available_runways.get();
+        if available == 0 {
+            return None;
+        }
+
+        self.available_runways.set(available - 1);
+        Some(RunwayPermit {
+            available: Rc::clone(&self.available_runways),
+        })
+    }
+
+    // Returns a runway slot to the pool, e.g. once a plane has finished its
+    // landing or takeoff roll.
+    fn release_runway(&self, permit: RunwayPermit) {
+        drop(permit);
+    }
+
+    // Keeps a runway slot occupied for `plane_id` beyond the decision call
+    // that acquired it, for as long as the plane's landing or takeoff roll
+    // is actually in progress.
+    fn occupy_runway(&mut self, plane_id: u8, permit: RunwayPermit) {
+        self.active_permits.insert(plane_id, permit);
+    }
+
+    // Frees the runway a plane was occupying once its landing or takeoff
+    // roll has finished. A no-op if the plane isn't occupying one.
+    pub fn complete_roll(&mut self, plane_id: u8) {
+        if let Some(permit) = self.active_permits.remove(&plane_id) {
+            self.release_runway(permit);
         }
     }
 
-    fn allow_landing(&mut self, plane: &Plane) -> ControllerResponse {
-        match self.check_weather() {
+    // Re-polls weather and re-attempts the oldest queued landing, advancing
+    // its attempt count or giving up according to the retry policy. Returns
+    // `None` if the holding pattern is empty, or if the oldest entry's
+    // backoff hasn't elapsed yet.
+    async fn tick(&mut self) -> Option<ControllerResponse> {
+        let (_, _, retry_at) = self.holding_pattern.front()?;
+        if SystemTime::now() < *retry_at {
+            return None;
+        }
+        let (plane, attempts, _) = self.holding_pattern.pop_front()?;
+
+        let permit = match self.acquire_runway() {
+            Some(permit) => permit,
+            None => {
+                self.record_event(plane.id, ControllerResponse::Hold, None);
+                self.holding_pattern
+                    .push_front((plane, attempts, SystemTime::now()));
+                return Some(ControllerResponse::Hold);
+            }
+        };
+
+        let (response, weather) = self.evaluate_landing(&plane).await;
+        match response {
+            ControllerResponse::AcceptLanding => {
+                self.add_plane(plane.id);
+                self.occupy_runway(plane.id, permit);
+            }
+            ControllerResponse::RejectLanding | ControllerResponse::Redirect => {
+                if let Some(backoff) = self.retry_policy.retry(&response, attempts + 1) {
+                    let retry_at = SystemTime::now() + backoff;
+                    self.holding_pattern
+                        .push_back((plane.clone(), attempts + 1, retry_at));
+                }
+                self.release_runway(permit);
+            }
+            _ => {
+                self.release_runway(permit);
+            }
+        };
+
+        self.record_event(plane.id, response.clone(), weather);
+        Some(response)
+    }
+
+    fn queue_for_retry(&mut self, plane: &Plane) {
+        self.holding_pattern
+            .push_back((plane.clone(), 0, SystemTime::now()));
+    }
+
+    // Removes a just-queued retry entry for `plane_id`, used when a caller
+    // (e.g. `AirportNetwork`) is about to land the plane elsewhere instead of
+    // waiting out the holding pattern here.
+    pub(crate) fn discard_queued_retry(&mut self, plane_id: u8) {
+        if matches!(self.holding_pattern.back(), Some((plane, _, _)) if plane.id == plane_id) {
+            self.holding_pattern.pop_back();
+        }
+    }
+
+    async fn evaluate_landing(&self, plane: &Plane) -> (ControllerResponse, Option<(Weather, i8)>) {
+        let observation = match self.check_weather().await {
+            Ok(observation) => observation,
+            Err(_) => return (ControllerResponse::Unavailable, None),
+        };
+
+        match &observation {
             (Weather::Stormy, _) => {
-                return ControllerResponse::RejectLanding;
+                return (ControllerResponse::RejectLanding, Some(observation));
+            }
+            (_, wind) if i16::from(*wind).abs() > i16::from(self.max_landing_wind) => {
+                return (ControllerResponse::RejectLanding, Some(observation));
             }
             (_, _) => {}
         };
 
         if self.plane_ids.contains(&plane.id) {
-            return ControllerResponse::RejectLanding;
+            return (ControllerResponse::RejectLanding, Some(observation));
         }
 
         if self.airport_capacity + 1 > self.airport_max_capacity {
-            return ControllerResponse::Redirect;
+            return (ControllerResponse::Redirect, Some(observation));
         }
 
-        self.add_plane(plane.id);
-        ControllerResponse::AcceptLanding
+        (ControllerResponse::AcceptLanding, Some(observation))
+    }
+
+    async fn allow_landing(&mut self, plane: &Plane) -> ControllerResponse {
+        let permit = match self.acquire_runway() {
+            Some(permit) => permit,
+            None => {
+                self.record_event(plane.id, ControllerResponse::Hold, None);
+                return ControllerResponse::Hold;
+            }
+        };
+
+        let (response, weather) = self.evaluate_landing(plane).await;
+        match response {
+            ControllerResponse::AcceptLanding => {
+                self.add_plane(plane.id);
+                self.occupy_runway(plane.id, permit);
+            }
+            ControllerResponse::RejectLanding | ControllerResponse::Redirect => {
+                self.queue_for_retry(plane);
+                self.release_runway(permit);
+            }
+            _ => {
+                self.release_runway(permit);
+            }
+        };
+
+        self.record_event(plane.id, response.clone(), weather);
+        response
     }
 
-    fn allow_takeoff(&mut self, plane: &Plane) -> ControllerResponse {
-        match self.check_weather() {
+    async fn evaluate_takeoff(&self, plane: &Plane) -> (ControllerResponse, Option<(Weather, i8)>) {
+        let observation = match self.check_weather().await {
+            Ok(observation) => observation,
+            Err(_) => return (ControllerResponse::Unavailable, None),
+        };
+
+        match &observation {
             (Weather::Stormy, _) => {
-                return ControllerResponse::RejectTakeoff;
+                return (ControllerResponse::RejectTakeoff, Some(observation));
+            }
+            (_, wind) if i16::from(*wind).abs() > i16::from(self.max_takeoff_wind) => {
+                return (ControllerResponse::RejectTakeoff, Some(observation));
             }
             (_, _) => {}
         };
 
         match plane.state {
-            PlaneState::Airborn => return ControllerResponse::RejectTakeoff,
+            PlaneState::Airborn => return (ControllerResponse::RejectTakeoff, Some(observation)),
             PlaneState::Landed => {}
         };
 
-        self.remove_plane(&plane.id);
+        (ControllerResponse::AllowTakeoff, Some(observation))
+    }
 
-        ControllerResponse::AllowTakeoff
+    async fn allow_takeoff(&mut self, plane: &Plane) -> ControllerResponse {
+        let permit = match self.acquire_runway() {
+            Some(permit) => permit,
+            None => {
+                self.record_event(plane.id, ControllerResponse::Hold, None);
+                return ControllerResponse::Hold;
+            }
+        };
+
+        let (response, weather) = self.evaluate_takeoff(plane).await;
+        if let ControllerResponse::AllowTakeoff = response {
+            self.remove_plane(&plane.id);
+            self.occupy_runway(plane.id, permit);
+        } else {
+            self.release_runway(permit);
+        }
+
+        self.record_event(plane.id, response.clone(), weather);
+        response
+    }
+
+    fn record_event(
+        &mut self,
+        plane_id: u8,
+        response: ControllerResponse,
+        observed_weather: Option<(Weather, i8)>,
+    ) {
+        self.events.push(ControllerEvent {
+            plane_id,
+            response,
+            observed_weather,
+            timestamp: SystemTime::now(),
+        });
+    }
+
+    pub fn stats(&self) -> ControllerStats {
+        let mut stats = ControllerStats {
+            accepted_landings: 0,
+            rejected_landings: 0,
+            redirects: 0,
+            allowed_takeoffs: 0,
+            rejected_takeoffs: 0,
+            holds: 0,
+            unavailable: 0,
+            current_occupancy: self.airport_capacity,
+        };
+
+        for event in &self.events {
+            match &event.response {
+                ControllerResponse::AcceptLanding => stats.accepted_landings += 1,
+                ControllerResponse::RejectLanding => stats.rejected_landings += 1,
+                ControllerResponse::Redirect | ControllerResponse::RedirectTo(_) => {
+                    stats.redirects += 1
+                }
+                ControllerResponse::AllowTakeoff => stats.allowed_takeoffs += 1,
+                ControllerResponse::RejectTakeoff => stats.rejected_takeoffs += 1,
+                ControllerResponse::Hold => stats.holds += 1,
+                ControllerResponse::Unavailable => stats.unavailable += 1,
+            }
+        }
+
+        stats
+    }
+
+    pub fn events_for(&self, plane_id: u8) -> Vec<&ControllerEvent> {
+        self.events
+            .iter()
+            .filter(|event| event.plane_id == plane_id)
+            .collect()
+    }
+
+    // The full decision log, in the order decisions were made.
+    pub fn events(&self) -> &[ControllerEvent] {
+        &self.events
+    }
+
+    // Decisions made at or after `since`, e.g. to answer "what happened
+    // during the last storm".
+    pub fn events_since(&self, since: SystemTime) -> Vec<&ControllerEvent> {
+        self.events
+            .iter()
+            .filter(|event| event.timestamp >= since)
+            .collect()
     }
 
     fn has_plane(&self, plane: &Plane) -> bool {
@@ -75,60 +347,199 @@ impl AirtrafficController {
         self.airport_max_capacity = max;
     }
 
-    fn check_weather(&self) -> (Weather, i8) {
-        self.weather_service.get_weather()
+    async fn check_weather(&self) -> Result<(Weather, i8), WeatherError> {
+        self.weather_service.get_weather().await
+    }
+
+    // Used by `AirportNetwork` to pick a redirect target.
+    pub(crate) fn has_spare_capacity(&self) -> bool {
+        self.airport_capacity + 1 <= self.airport_max_capacity
+    }
+
+    pub(crate) async fn is_stormy(&self) -> bool {
+        matches!(self.check_weather().await, Ok((Weather::Stormy, _)))
     }
 }
 
-#[derive(Eq, PartialEq, Debug)]
+#[derive(Clone, Eq, PartialEq, Debug)]
 pub enum ControllerResponse {
     AcceptLanding,
     RejectLanding,
     Redirect,
     AllowTakeoff,
     RejectTakeoff,
+    Hold,
+    Unavailable,
+    RedirectTo(String),
+}
+
+// An audit record of a single landing or takeoff decision.
+pub struct ControllerEvent {
+    pub plane_id: u8,
+    pub response: ControllerResponse,
+    pub observed_weather: Option<(Weather, i8)>,
+    pub timestamp: SystemTime,
+}
+
+// Counts of past decisions plus the airport's current occupancy, as returned
+// by `AirtrafficController::stats`.
+pub struct ControllerStats {
+    pub accepted_landings: usize,
+    pub rejected_landings: usize,
+    pub redirects: usize,
+    pub allowed_takeoffs: usize,
+    pub rejected_takeoffs: usize,
+    pub holds: usize,
+    pub unavailable: usize,
+    pub current_occupancy: usize,
+}
+
+// RAII guard over a runway slot, borrowed from tower's in-flight limit
+// middleware. Returns the slot to the pool when dropped.
+pub struct RunwayPermit {
+    available: Rc<Cell<usize>>,
+}
+
+impl Drop for RunwayPermit {
+    fn drop(&mut self) {
+        self.available.set(self.available.get() + 1);
+    }
+}
+
+// Modeled on tower-retry's `Policy` trait: decides whether a rejected
+// operation should be retried, and if so, after what backoff.
+pub trait RetryPolicy {
+    fn retry(&self, response: &ControllerResponse, attempts: u32) -> Option<Duration>;
 }
 
-#[derive(Ord, PartialOrd, Eq, PartialEq)]
+pub struct ExponentialBackoff {
+    base: Duration,
+    max_attempts: u32,
+}
+
+impl ExponentialBackoff {
+    pub fn new(base: Duration, max_attempts: u32) -> Self {
+        Self { base, max_attempts }
+    }
+}
+
+impl RetryPolicy for ExponentialBackoff {
+    fn retry(&self, response: &ControllerResponse, attempts: u32) -> Option<Duration> {
+        if attempts >= self.max_attempts {
+            return None;
+        }
+
+        match response {
+            ControllerResponse::RejectLanding | ControllerResponse::Redirect => {
+                Some(self.base * 2u32.pow(attempts))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct Plane {
     id: u8,
     state: PlaneState,
 }
 
-#[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[derive(Clone, Ord, PartialOrd, Eq, PartialEq, Debug)]
 pub enum PlaneState {
     Landed,
     Airborn,
 }
 
 impl Plane {
-    pub fn request_takeoff(&mut self, controller: &mut AirtrafficController) -> ControllerResponse {
-        if let ControllerResponse::RejectTakeoff = controller.allow_takeoff(self) {
-            return ControllerResponse::RejectTakeoff;
+    pub async fn request_takeoff(
+        &mut self,
+        controller: &mut AirtrafficController,
+    ) -> ControllerResponse {
+        match controller.allow_takeoff(self).await {
+            response @ (ControllerResponse::RejectTakeoff
+            | ControllerResponse::Hold
+            | ControllerResponse::Unavailable) => return response,
+            _ => {}
         };
+
         self.state = PlaneState::Airborn;
         ControllerResponse::AllowTakeoff
     }
 
-    pub fn request_landing(&mut self, controller: &mut AirtrafficController) -> ControllerResponse {
-        match controller.allow_landing(self) {
-            ControllerResponse::RejectLanding => {
-                return ControllerResponse::RejectLanding;
-            }
-            ControllerResponse::Redirect => {
-                return ControllerResponse::Redirect;
-            }
+    pub async fn request_landing(
+        &mut self,
+        controller: &mut AirtrafficController,
+    ) -> ControllerResponse {
+        match controller.allow_landing(self).await {
+            response @ (ControllerResponse::RejectLanding
+            | ControllerResponse::Redirect
+            | ControllerResponse::Hold
+            | ControllerResponse::Unavailable) => return response,
             _ => {}
         };
 
         self.state = PlaneState::Landed;
         ControllerResponse::AcceptLanding
     }
+
+    // Signals that this plane's landing or takeoff roll has finished,
+    // freeing the runway it was occupying for the next operation.
+    pub fn confirm_roll_complete(&self, controller: &mut AirtrafficController) {
+        controller.complete_roll(self.id);
+    }
+
+    // Tries the home airport first; if it's full or storm-bound, transparently
+    // lands at whichever peer airport the network finds with spare capacity
+    // and non-stormy weather.
+    pub async fn request_landing_in_network(
+        &mut self,
+        network: &mut AirportNetwork,
+        home: &str,
+    ) -> ControllerResponse {
+        let response = {
+            let controller = network
+                .airport_mut(home)
+                .expect("unknown airport id in network");
+            self.request_landing(controller).await
+        };
+
+        match response {
+            ControllerResponse::Redirect | ControllerResponse::RejectLanding => {
+                // The home airport queued this plane for a later retry; since
+                // we're about to try landing it at a peer instead, drop that
+                // entry so it can't also land at home on a later tick.
+                network
+                    .airport_mut(home)
+                    .expect("unknown airport id in network")
+                    .discard_queued_retry(self.id);
+
+                if let Some(target) = network.find_redirect_target(home).await {
+                    let alternate = network
+                        .airport_mut(&target)
+                        .expect("redirect target vanished from network");
+                    if let ControllerResponse::AcceptLanding =
+                        self.request_landing(alternate).await
+                    {
+                        return ControllerResponse::RedirectTo(target);
+                    }
+                }
+                response
+            }
+            _ => response,
+        }
+    }
 }
 
+#[async_trait]
 #[automock]
 pub trait WeatherService {
-    fn get_weather(&self) -> (Weather, i8);
+    async fn get_weather(&self) -> Result<(Weather, i8), WeatherError>;
+}
+
+#[derive(Clone, Debug)]
+pub enum WeatherError {
+    Request(String),
+    UnrecognizedCondition(String),
 }
 
 #[derive(Clone)]
@@ -149,10 +560,11 @@ mod test {
     // As an air traffic controller
     // So I can get passengers to a destination
     // I want to instruct a plane to land at an airport
-    #[test]
-    fn plane_can_land() {
+    #[tokio::test]
+    async fn plane_can_land() {
         let mut mock = Box::new(MockWeatherService::new());
-        mock.expect_get_weather().return_const((Weather::Clear, 10));
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
 
         let mut controller = AirtrafficController::new(mock, vec![]);
         let mut plane = Plane {
@@ -161,17 +573,18 @@ mod test {
         };
 
         assert_eq!(false, controller.has_plane(&plane));
-        plane.request_landing(&mut controller);
+        plane.request_landing(&mut controller).await;
         assert_eq!(true, controller.has_plane(&plane));
     }
 
     // As an air traffic controller
     // So I can make sure there are no collisions
     // I want to be sure a plane can't request to land if it already has
-    #[test]
-    fn plane_already_landed() {
+    #[tokio::test]
+    async fn plane_already_landed() {
         let mut mock = Box::new(MockWeatherService::new());
-        mock.expect_get_weather().return_const((Weather::Clear, 10));
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
 
         let mut controller = AirtrafficController::new(mock, vec![1]);
         let mut plane = Plane {
@@ -180,17 +593,18 @@ mod test {
         };
 
         assert_eq!(true, controller.has_plane(&plane));
-        plane.request_landing(&mut controller);
+        plane.request_landing(&mut controller).await;
         assert_eq!(true, controller.has_plane(&plane));
     }
 
     // As an air traffic controller
     // So I can get passengers on the way to their destination
     // I want to instruct a plane to take off from an airport and confirm that it is no longer in the airport
-    #[test]
-    fn plane_can_take_off() {
+    #[tokio::test]
+    async fn plane_can_take_off() {
         let mut mock = Box::new(MockWeatherService::new());
-        mock.expect_get_weather().return_const((Weather::Clear, 10));
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
 
         let mut controller = AirtrafficController::new(mock, vec![1]);
         let mut plane = Plane {
@@ -201,7 +615,7 @@ mod test {
         assert_eq!(PlaneState::Landed, plane.state);
         assert_eq!(true, controller.has_plane(&plane));
 
-        plane.request_takeoff(&mut controller);
+        plane.request_takeoff(&mut controller).await;
 
         assert_eq!(PlaneState::Airborn, plane.state);
         assert_eq!(false, controller.has_plane(&plane));
@@ -210,10 +624,11 @@ mod test {
     // As an air traffic controller
     // To ensure safety
     // I want to prevent landing when the airport is full
-    #[test]
-    fn plane_will_redirect_if_airport_is_full() {
+    #[tokio::test]
+    async fn plane_will_redirect_if_airport_is_full() {
         let mut mock = Box::new(MockWeatherService::new());
-        mock.expect_get_weather().return_const((Weather::Clear, 10));
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
 
         let mut controller = AirtrafficController::new(mock, vec![]);
         let mut plane = Plane {
@@ -226,7 +641,7 @@ mod test {
         controller.set_max_capacity(0);
         assert_eq!(
             ControllerResponse::Redirect,
-            plane.request_landing(&mut controller)
+            plane.request_landing(&mut controller).await
         );
     }
 
@@ -246,11 +661,11 @@ mod test {
     // As an air traffic controller
     // To ensure safety
     // I want to prevent takeoff when weather is stormy
-    #[test]
-    fn prevent_takeoff_during_storm() {
+    #[tokio::test]
+    async fn prevent_takeoff_during_storm() {
         let mut mock = Box::new(MockWeatherService::new());
         mock.expect_get_weather()
-            .return_const((Weather::Stormy, -10));
+            .returning(|| Box::pin(async { Ok((Weather::Stormy, -10)) }));
 
         let mut controller = AirtrafficController::new(mock, vec![1]);
         let mut plane = Plane {
@@ -263,7 +678,7 @@ mod test {
 
         assert_eq!(
             ControllerResponse::RejectTakeoff,
-            plane.request_takeoff(&mut controller)
+            plane.request_takeoff(&mut controller).await
         );
 
         assert_eq!(true, controller.has_plane(&plane));
@@ -273,11 +688,11 @@ mod test {
     // As an air traffic controller
     // To ensure safety
     // I want to prevent landing when weather is stormy
-    #[test]
-    fn prevent_landing_during_storm() {
+    #[tokio::test]
+    async fn prevent_landing_during_storm() {
         let mut mock = Box::new(MockWeatherService::new());
         mock.expect_get_weather()
-            .return_const((Weather::Stormy, -10));
+            .returning(|| Box::pin(async { Ok((Weather::Stormy, -10)) }));
 
         let mut controller = AirtrafficController::new(mock, vec![]);
         let mut plane = Plane {
@@ -290,10 +705,459 @@ mod test {
 
         assert_eq!(
             ControllerResponse::RejectLanding,
-            plane.request_landing(&mut controller)
+            plane.request_landing(&mut controller).await
         );
 
         assert_eq!(false, controller.has_plane(&plane));
         assert_eq!(PlaneState::Airborn, plane.state);
     }
+
+    // As an air traffic controller
+    // To avoid abandoning planes that were rejected or redirected
+    // I want a holding pattern that retries the oldest queued landing on each tick
+    #[tokio::test]
+    async fn rejected_plane_is_queued_and_retried_once_weather_clears() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .times(1)
+            .returning(|| Box::pin(async { Ok((Weather::Stormy, -10)) }));
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::RejectLanding,
+            plane.request_landing(&mut controller).await
+        );
+        assert_eq!(false, controller.has_plane(&plane));
+
+        assert_eq!(
+            Some(ControllerResponse::AcceptLanding),
+            controller.tick().await
+        );
+        assert_eq!(
+            true,
+            controller.has_plane(&Plane {
+                id: 1,
+                state: PlaneState::Landed
+            })
+        );
+    }
+
+    // As an air traffic controller
+    // So that I don't hold a plane indefinitely
+    // I want the retry policy to give up after its maximum attempts
+    #[tokio::test]
+    async fn queued_plane_is_dropped_once_retry_policy_gives_up() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Stormy, -10)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        controller.set_retry_policy(Box::new(ExponentialBackoff::new(Duration::from_secs(1), 1)));
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::RejectLanding,
+            plane.request_landing(&mut controller).await
+        );
+        assert_eq!(
+            Some(ControllerResponse::RejectLanding),
+            controller.tick().await
+        );
+        assert_eq!(None, controller.tick().await);
+    }
+
+    // As an air traffic controller
+    // So that repeated retries don't hammer the weather service
+    // I want a retried plane to wait out its computed backoff before ticking again
+    #[tokio::test]
+    async fn queued_plane_is_not_retried_before_its_backoff_elapses() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Stormy, -10)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        controller.set_retry_policy(Box::new(ExponentialBackoff::new(Duration::from_secs(3600), 3)));
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::RejectLanding,
+            plane.request_landing(&mut controller).await
+        );
+        assert_eq!(
+            Some(ControllerResponse::RejectLanding),
+            controller.tick().await
+        );
+
+        // The just-computed backoff is two hours away, so immediately ticking
+        // again must not re-attempt the landing yet.
+        assert_eq!(None, controller.tick().await);
+    }
+
+    // As an air traffic controller
+    // To avoid runway collisions
+    // I want landings to be held when every runway is already occupied
+    #[tokio::test]
+    async fn plane_is_held_when_no_runway_is_available() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::with_runways(mock, vec![], 0);
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::Hold,
+            plane.request_landing(&mut controller).await
+        );
+        assert_eq!(false, controller.has_plane(&plane));
+    }
+
+    // As an air traffic controller
+    // So that a busy runway doesn't starve later landings
+    // I want a finished roll to free its slot for the next operation
+    #[tokio::test]
+    async fn runway_is_freed_after_a_landing_completes() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::with_runways(mock, vec![], 1);
+        let mut first = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+        let mut second = Plane {
+            id: 2,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::AcceptLanding,
+            first.request_landing(&mut controller).await
+        );
+        assert_eq!(
+            ControllerResponse::Hold,
+            second.request_landing(&mut controller).await
+        );
+
+        first.confirm_roll_complete(&mut controller);
+
+        assert_eq!(
+            ControllerResponse::AcceptLanding,
+            second.request_landing(&mut controller).await
+        );
+    }
+
+    // As an air traffic controller
+    // To ensure safety for smaller aircraft
+    // I want to reject landing when crosswind exceeds the configured threshold
+    #[tokio::test]
+    async fn prevent_landing_when_wind_exceeds_threshold() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, -25)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        controller.set_max_landing_wind(20);
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::RejectLanding,
+            plane.request_landing(&mut controller).await
+        );
+        assert_eq!(false, controller.has_plane(&plane));
+    }
+
+    // As an air traffic controller
+    // To ensure safety for smaller aircraft
+    // I want to reject takeoff when crosswind exceeds the configured threshold
+    #[tokio::test]
+    async fn prevent_takeoff_when_wind_exceeds_threshold() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 25)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![1]);
+        controller.set_max_takeoff_wind(20);
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Landed,
+        };
+
+        assert_eq!(
+            ControllerResponse::RejectTakeoff,
+            plane.request_takeoff(&mut controller).await
+        );
+        assert_eq!(true, controller.has_plane(&plane));
+    }
+
+    // As an air traffic controller
+    // To keep the crosswind check from crashing on extreme readings
+    // I want a wind speed of i8::MIN to be rejected rather than panic
+    #[tokio::test]
+    async fn extreme_negative_wind_reading_does_not_panic() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, i8::MIN)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        controller.set_max_landing_wind(20);
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::RejectLanding,
+            plane.request_landing(&mut controller).await
+        );
+    }
+
+    // As an air traffic controller
+    // So that I don't act on stale or missing weather data
+    // I want landing and takeoff to report Unavailable when the weather service errs
+    #[tokio::test]
+    async fn landing_is_unavailable_when_weather_service_errs() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Err(WeatherError::Request("timed out".into())) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::Unavailable,
+            plane.request_landing(&mut controller).await
+        );
+        assert_eq!(false, controller.has_plane(&plane));
+    }
+
+    // As an air traffic controller
+    // So that a full airport isn't a dead end
+    // I want a plane to transparently land at a peer airport with room to take it
+    #[tokio::test]
+    async fn plane_lands_at_peer_airport_when_home_is_full() {
+        let mut home_weather = Box::new(MockWeatherService::new());
+        home_weather
+            .expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut peer_weather = Box::new(MockWeatherService::new());
+        peer_weather
+            .expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut home = AirtrafficController::new(home_weather, vec![]);
+        home.set_max_capacity(0);
+        let peer = AirtrafficController::new(peer_weather, vec![]);
+
+        let mut network = AirportNetwork::new();
+        network.add_airport("home", home);
+        network.add_airport("peer", peer);
+
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::RedirectTo("peer".to_string()),
+            plane
+                .request_landing_in_network(&mut network, "home")
+                .await
+        );
+        assert_eq!(PlaneState::Landed, plane.state);
+    }
+
+    // As an air traffic controller
+    // So operators aren't told a plane landed somewhere it didn't
+    // I want the bare Redirect response when no peer airport can take the plane
+    #[tokio::test]
+    async fn redirect_is_returned_when_no_peer_airport_has_room() {
+        let mut home_weather = Box::new(MockWeatherService::new());
+        home_weather
+            .expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut peer_weather = Box::new(MockWeatherService::new());
+        peer_weather
+            .expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Stormy, -10)) }));
+
+        let mut home = AirtrafficController::new(home_weather, vec![]);
+        home.set_max_capacity(0);
+        let peer = AirtrafficController::new(peer_weather, vec![]);
+
+        let mut network = AirportNetwork::new();
+        network.add_airport("home", home);
+        network.add_airport("peer", peer);
+
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::Redirect,
+            plane
+                .request_landing_in_network(&mut network, "home")
+                .await
+        );
+        assert_eq!(PlaneState::Airborn, plane.state);
+    }
+
+    // As an operator
+    // So I can audit controller decisions
+    // I want stats() to tally decisions by kind and report current occupancy
+    #[tokio::test]
+    async fn stats_tally_decisions_and_report_occupancy() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather().returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![2]);
+        let mut accepted = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+        let mut rejected = Plane {
+            id: 2,
+            state: PlaneState::Airborn,
+        };
+
+        accepted.request_landing(&mut controller).await;
+        rejected.request_landing(&mut controller).await;
+
+        let stats = controller.stats();
+        assert_eq!(1, stats.accepted_landings);
+        assert_eq!(1, stats.rejected_landings);
+        assert_eq!(2, stats.current_occupancy);
+    }
+
+    // As an operator
+    // So I can answer "what happened to this plane"
+    // I want events_for() to return only the events for the requested plane
+    #[tokio::test]
+    async fn events_for_returns_only_events_for_the_requested_plane() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather().returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        let mut first = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+        let mut second = Plane {
+            id: 2,
+            state: PlaneState::Airborn,
+        };
+
+        first.request_landing(&mut controller).await;
+        second.request_landing(&mut controller).await;
+
+        let events = controller.events_for(1);
+        assert_eq!(1, events.len());
+        assert_eq!(1, events[0].plane_id);
+    }
+
+    // As an operator
+    // So I can review everything that happened, not just one plane at a time
+    // I want events() to return the full decision log in order
+    #[tokio::test]
+    async fn events_returns_the_full_decision_log_in_order() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather().returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        let mut first = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+        let mut second = Plane {
+            id: 2,
+            state: PlaneState::Airborn,
+        };
+
+        first.request_landing(&mut controller).await;
+        second.request_landing(&mut controller).await;
+
+        let events = controller.events();
+        assert_eq!(2, events.len());
+        assert_eq!(1, events[0].plane_id);
+        assert_eq!(2, events[1].plane_id);
+    }
+
+    // As an operator
+    // So I can answer "how many planes did we redirect during the last storm"
+    // I want events_since() to return only decisions made at or after a timestamp
+    #[tokio::test]
+    async fn events_since_returns_only_events_at_or_after_the_given_time() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather().returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::new(mock, vec![]);
+        let mut first = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+        let mut second = Plane {
+            id: 2,
+            state: PlaneState::Airborn,
+        };
+
+        first.request_landing(&mut controller).await;
+        let cutoff = SystemTime::now();
+        second.request_landing(&mut controller).await;
+
+        let events = controller.events_since(cutoff);
+        assert_eq!(1, events.len());
+        assert_eq!(2, events[0].plane_id);
+    }
+
+    // As an operator
+    // So stats() can actually report how often landings and takeoffs were held
+    // I want a Hold decision to be recorded in the event log
+    #[tokio::test]
+    async fn hold_decisions_are_recorded_and_counted_in_stats() {
+        let mut mock = Box::new(MockWeatherService::new());
+        mock.expect_get_weather()
+            .returning(|| Box::pin(async { Ok((Weather::Clear, 10)) }));
+
+        let mut controller = AirtrafficController::with_runways(mock, vec![], 0);
+        let mut plane = Plane {
+            id: 1,
+            state: PlaneState::Airborn,
+        };
+
+        assert_eq!(
+            ControllerResponse::Hold,
+            plane.request_landing(&mut controller).await
+        );
+
+        let events = controller.events_for(1);
+        assert_eq!(1, events.len());
+        assert_eq!(ControllerResponse::Hold, events[0].response);
+        assert_eq!(1, controller.stats().holds);
+    }
 }
@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use crate::AirtrafficController;
+
+// Holds several `AirtrafficController`s keyed by airport id so that a plane
+// rejected by one airport can be routed to a peer with room to take it.
+pub struct AirportNetwork {
+    airports: HashMap<String, AirtrafficController>,
+}
+
+impl AirportNetwork {
+    pub fn new() -> Self {
+        Self {
+            airports: HashMap::new(),
+        }
+    }
+
+    pub fn add_airport(&mut self, id: impl Into<String>, controller: AirtrafficController) {
+        self.airports.insert(id.into(), controller);
+    }
+
+    pub(crate) fn airport_mut(&mut self, id: &str) -> Option<&mut AirtrafficController> {
+        self.airports.get_mut(id)
+    }
+
+    // Finds a peer airport (other than `exclude`) with spare capacity and
+    // non-stormy weather.
+    pub(crate) async fn find_redirect_target(&self, exclude: &str) -> Option<String> {
+        for (id, controller) in &self.airports {
+            if id == exclude {
+                continue;
+            }
+
+            if controller.has_spare_capacity() && !controller.is_stormy().await {
+                return Some(id.clone());
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,75 @@
+use airtraffic_controller::{HttpWeatherService, Weather, WeatherService};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn maps_stormy_metar_condition_to_stormy_weather() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/weather"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "condition": "TS",
+            "wind_speed_kt": -18
+        })))
+        .mount(&server)
+        .await;
+
+    let service = HttpWeatherService::new(format!("{}/weather", server.uri()));
+    let (weather, wind) = service.get_weather().await.unwrap();
+
+    assert!(matches!(weather, Weather::Stormy));
+    assert_eq!(-18, wind);
+}
+
+#[tokio::test]
+async fn maps_raining_metar_condition_to_raining_weather() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/weather"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "condition": "RA",
+            "wind_speed_kt": 12
+        })))
+        .mount(&server)
+        .await;
+
+    let service = HttpWeatherService::new(format!("{}/weather", server.uri()));
+    let (weather, wind) = service.get_weather().await.unwrap();
+
+    assert!(matches!(weather, Weather::Raining));
+    assert_eq!(12, wind);
+}
+
+#[tokio::test]
+async fn unrecognized_condition_code_is_a_weather_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/weather"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "condition": "VA",
+            "wind_speed_kt": 0
+        })))
+        .mount(&server)
+        .await;
+
+    let service = HttpWeatherService::new(format!("{}/weather", server.uri()));
+
+    assert!(service.get_weather().await.is_err());
+}
+
+#[tokio::test]
+async fn non_success_status_is_a_weather_error_even_with_a_json_body() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/weather"))
+        .respond_with(ResponseTemplate::new(500).set_body_json(serde_json::json!({
+            "condition": "TS",
+            "wind_speed_kt": 10
+        })))
+        .mount(&server)
+        .await;
+
+    let service = HttpWeatherService::new(format!("{}/weather", server.uri()));
+
+    assert!(service.get_weather().await.is_err());
+}